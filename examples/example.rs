@@ -1,11 +1,15 @@
-use smbus_pec::pec;
+use smbus_pec::{pec, pec_const};
 
 const ADDRESS: u8 = 0x5A;
 const REGISTER: u8 = 0x06;
 
+// Computed at compile time since the command sequence is fixed.
+const CHECKSUM: u8 = pec_const(&[ADDRESS << 1, REGISTER, 0xAB, 0xCD]);
+
 fn main() {
     let pec_write = pec(&[ADDRESS << 1, REGISTER, 0xAB, 0xCD]);
     println!("PEC: {}", pec_write); // prints 95
+    println!("PEC (const): {}", CHECKSUM); // prints 95
 
     let data = [ADDRESS << 1, REGISTER, (ADDRESS << 1) + 1, 38, 58];
     let pec_write_read = pec(&data);
@@ -0,0 +1,34 @@
+//! Implementation of the [RustCrypto `digest`](digest) crate traits for [`Pec`].
+//!
+//! This lets `Pec` be driven by any code written against `digest::Digest`, including
+//! HMAC wrappers and generic file/stream checkers, while preserving the existing
+//! lightweight `core::hash::Hasher` API.
+
+use core::hash::Hasher;
+use digest::{consts::U1, FixedOutput, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+use crate::Pec;
+
+impl HashMarker for Pec {}
+
+impl OutputSizeUser for Pec {
+    type OutputSize = U1;
+}
+
+impl Update for Pec {
+    fn update(&mut self, data: &[u8]) {
+        Hasher::write(self, data);
+    }
+}
+
+impl FixedOutput for Pec {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out[0] = Hasher::finish(&self) as u8;
+    }
+}
+
+impl Reset for Pec {
+    fn reset(&mut self) {
+        *self = Pec::new();
+    }
+}
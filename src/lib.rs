@@ -135,11 +135,76 @@
 //! let value = data[0];
 //! ```
 //!
+//! ## Using the `embedded-hal` wrapper
+//!
+//! With the `embedded-hal` feature enabled, [`transaction::PecI2c`] wraps any
+//! `embedded_hal::i2c::I2c` implementation and applies the steps shown above
+//! automatically, so callers only deal with SMBus-style operations directly.
+//!
+//! ## Computing a PEC at compile time
+//!
+//! Fixed command sequences can have their PEC computed once at compile time with
+//! [`pec_const`], rather than hard-coding the resulting checksum byte.
+//!
+//! ```rust
+//! use smbus_pec::pec_const;
+//! const ADDR: u8 = 0x5A;
+//! const COMMAND: u8 = 0x06;
+//! const VALUE: u8 = 0xAB;
+//! const CHECKSUM: u8 = pec_const(&[ADDR << 1, COMMAND, VALUE]);
+//! ```
+//!
+//! ## Framing a message with `append_pec`/`verify_pec`
+//!
+//! ```rust
+//! use smbus_pec::{append_pec, verify_pec};
+//! const ADDR: u8 = 0x5A;
+//! let command = 0x06;
+//! let value = 0xAB;
+//!
+//! let mut frame = [ADDR << 1, command, value, 0];
+//! let len = append_pec(&mut frame).unwrap();
+//! assert!(verify_pec(&frame[..len]));
+//! ```
+//!
+//! ## Using `Pec` as a RustCrypto `digest`
+//!
+//! With the `digest` feature enabled, `Pec` implements the [`digest`](https://docs.rs/digest)
+//! crate traits (`Update`, `FixedOutput`, `Reset`, ...), so it can be driven by any code
+//! written against `digest::Digest`.
+//!
 
 #![doc(html_root_url = "https://docs.rs/smbus-pec/0.1.0")]
 #![deny(unsafe_code, missing_docs)]
 #![no_std]
 
+/// Calculate SMBus Packet Error Code over transmitted data, usable in `const` contexts.
+///
+/// The input data array must contain the complete message including address and
+/// read/write bit.
+///
+/// This always uses the bit-by-bit algorithm, since the lookup table used by the
+/// `lookup-table` feature cannot easily be indexed in a `const fn`. Prefer [`pec`] for
+/// runtime calculations, as it may be faster when the `lookup-table` feature is enabled.
+pub const fn pec_const(data: &[u8]) -> u8 {
+    let mut crc: u8 = 0;
+    let mut i = 0;
+    while i < data.len() {
+        crc ^= data[i];
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
 #[cfg(not(feature = "lookup-table"))]
 mod default_impl {
     use embedded_crc_macros::{crc8, crc8_hasher};
@@ -193,3 +258,52 @@ pub use crate::lookup_table_impl::{pec, Pec};
 
 #[cfg(feature = "lookup-table")]
 include!(concat!(env!("OUT_DIR"), "/lookup_table.rs"));
+
+impl Pec {
+    /// Finish hashing and compare the result against an expected PEC byte.
+    ///
+    /// Equivalent to comparing `self.finish() as u8` against `expected`, but more
+    /// convenient for callers that stream bytes through repeated `write()` calls and
+    /// then check the result against a received PEC byte in one step.
+    pub fn verify(&mut self, expected: u8) -> bool {
+        core::hash::Hasher::finish(self) as u8 == expected
+    }
+}
+
+/// Error indicating that a frame buffer was too small to hold the trailing PEC byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FramingError;
+
+/// Compute the PEC over `buf[..buf.len() - 1]` and store it in the last byte of `buf`,
+/// returning the total length of the resulting frame.
+///
+/// Returns `Err(FramingError)` if `buf` is empty, since there is then no last byte to
+/// store the PEC in.
+pub fn append_pec(buf: &mut [u8]) -> Result<usize, FramingError> {
+    let n = buf.len();
+    if n == 0 {
+        return Err(FramingError);
+    }
+    buf[n - 1] = pec(&buf[..n - 1]);
+    Ok(n)
+}
+
+/// Recompute the PEC over all but the last byte of `frame` and compare it against the
+/// trailing byte.
+///
+/// Returns `false` if `frame` is empty.
+pub fn verify_pec(frame: &[u8]) -> bool {
+    match frame.len() {
+        0 => false,
+        n => pec(&frame[..n - 1]) == frame[n - 1],
+    }
+}
+
+/// An `embedded-hal` `I2c` wrapper that transparently applies PEC to SMBus transactions.
+///
+/// Enabled through the `embedded-hal` feature.
+#[cfg(feature = "embedded-hal")]
+pub mod transaction;
+
+#[cfg(feature = "digest")]
+mod digest_impl;
@@ -0,0 +1,146 @@
+//! An [`embedded-hal`](embedded_hal) [`I2c`] wrapper that transparently applies the
+//! Packet Error Code to SMBus-style transactions.
+//!
+//! This hides the manual byte-stream assembly shown in the crate-level documentation
+//! (the address + read/write bit framing, appending the PEC on sends, recomputing and
+//! comparing it on receives) behind a small set of SMBus operations.
+
+use crate::pec;
+use embedded_hal::i2c::{I2c, SevenBitAddress};
+
+/// Maximum number of data bytes in an SMBus block transfer.
+const MAX_BLOCK_LEN: usize = 32;
+
+/// Error type for [`PecI2c`] operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PecError<E> {
+    /// An error occurred in the underlying `I2c` implementation.
+    I2c(E),
+    /// The Packet Error Code received from the device did not match the one
+    /// computed over the received data.
+    Pec,
+    /// The block data did not fit in the SMBus block size limit of 32 bytes.
+    InvalidLength,
+}
+
+impl<E> From<E> for PecError<E> {
+    fn from(error: E) -> Self {
+        PecError::I2c(error)
+    }
+}
+
+/// Wraps an `embedded-hal` [`I2c`] implementation and transparently appends/verifies
+/// the Packet Error Code on every SMBus transaction.
+///
+/// See the [module-level documentation](crate::transaction) for details.
+#[derive(Debug)]
+pub struct PecI2c<I2C> {
+    i2c: I2C,
+}
+
+impl<I2C> PecI2c<I2C> {
+    /// Create a new `PecI2c` wrapping the provided `I2c` implementation.
+    pub fn new(i2c: I2C) -> Self {
+        PecI2c { i2c }
+    }
+
+    /// Release the wrapped `I2c` implementation.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+}
+
+impl<I2C, E> PecI2c<I2C>
+where
+    I2C: I2c<SevenBitAddress, Error = E>,
+{
+    /// Write a single byte of data to `command`, appending the computed PEC.
+    pub fn write_byte(&mut self, address: u8, command: u8, data: u8) -> Result<(), PecError<E>> {
+        let checksum = pec(&[address << 1, command, data]);
+        Ok(self.i2c.write(address, &[command, data, checksum])?)
+    }
+
+    /// Write a word (two bytes, least significant byte first) to `command`,
+    /// appending the computed PEC.
+    pub fn write_word(&mut self, address: u8, command: u8, data: u16) -> Result<(), PecError<E>> {
+        let [lsb, msb] = data.to_le_bytes();
+        let checksum = pec(&[address << 1, command, lsb, msb]);
+        Ok(self.i2c.write(address, &[command, lsb, msb, checksum])?)
+    }
+
+    /// Read a single byte of data from `command`, verifying the received PEC.
+    pub fn read_byte(&mut self, address: u8, command: u8) -> Result<u8, PecError<E>> {
+        let mut data = [0; 2];
+        self.i2c.write_read(address, &[command], &mut data)?;
+        let checksum = pec(&[address << 1, command, (address << 1) | 1, data[0]]);
+        if checksum != data[1] {
+            return Err(PecError::Pec);
+        }
+        Ok(data[0])
+    }
+
+    /// Read a word (two bytes, least significant byte first) from `command`,
+    /// verifying the received PEC.
+    pub fn read_word(&mut self, address: u8, command: u8) -> Result<u16, PecError<E>> {
+        let mut data = [0; 3];
+        self.i2c.write_read(address, &[command], &mut data)?;
+        let checksum = pec(&[
+            address << 1,
+            command,
+            (address << 1) | 1,
+            data[0],
+            data[1],
+        ]);
+        if checksum != data[2] {
+            return Err(PecError::Pec);
+        }
+        Ok(u16::from_le_bytes([data[0], data[1]]))
+    }
+
+    /// Write an SMBus block of up to 32 bytes to `command`, appending the computed PEC.
+    ///
+    /// The byte count is sent ahead of `data` as required by the SMBus block protocol.
+    pub fn block_write(
+        &mut self,
+        address: u8,
+        command: u8,
+        data: &[u8],
+    ) -> Result<(), PecError<E>> {
+        if data.len() > MAX_BLOCK_LEN {
+            return Err(PecError::InvalidLength);
+        }
+        let mut buf = [0; 4 + MAX_BLOCK_LEN];
+        buf[0] = address << 1;
+        buf[1] = command;
+        buf[2] = data.len() as u8;
+        buf[3..3 + data.len()].copy_from_slice(data);
+        buf[3 + data.len()] = pec(&buf[..3 + data.len()]);
+        Ok(self.i2c.write(address, &buf[1..4 + data.len()])?)
+    }
+
+    /// Read an SMBus block of up to 32 bytes from `command`, verifying the received PEC.
+    ///
+    /// Returns the number of bytes written into `data`, which must be at least 32 bytes long.
+    pub fn block_read(
+        &mut self,
+        address: u8,
+        command: u8,
+        data: &mut [u8],
+    ) -> Result<usize, PecError<E>> {
+        let mut buf = [0; 1 + MAX_BLOCK_LEN + 1];
+        self.i2c.write_read(address, &[command], &mut buf)?;
+        let count = (buf[0] as usize).min(MAX_BLOCK_LEN);
+        let mut pec_input = [0; 4 + MAX_BLOCK_LEN];
+        pec_input[0] = address << 1;
+        pec_input[1] = command;
+        pec_input[2] = (address << 1) | 1;
+        pec_input[3] = buf[0];
+        pec_input[4..4 + count].copy_from_slice(&buf[1..1 + count]);
+        let checksum = pec(&pec_input[..4 + count]);
+        if checksum != buf[1 + count] {
+            return Err(PecError::Pec);
+        }
+        data[..count].copy_from_slice(&buf[1..1 + count]);
+        Ok(count)
+    }
+}